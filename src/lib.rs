@@ -6,25 +6,105 @@ use core::marker::PhantomData;
 use core::ptr;
 use std::ptr::NonNull;
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq)]
 pub struct PayloadPointer<T: ?Sized + Pointee> {
     ptr: NonNull<()>,
     meta: <T as Pointee>::Metadata,
     _marker: PhantomData<*const T>,
 }
 
+// Implemented by hand instead of derived: `#[derive(..)]` would add a spurious
+// `T: Trait` bound (derive looks at the generic parameter, not at how `PhantomData`
+// actually uses it), which would rule out `T`s that are only `?Sized`, like
+// `RawSlice2D<_>`. Everything we actually need (`T::Metadata: Copy` etc.) already
+// comes from the `Pointee::Metadata` supertrait bounds.
+impl<T: ?Sized + Pointee> Clone for PayloadPointer<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: ?Sized + Pointee> Copy for PayloadPointer<T> {}
+
+impl<T: ?Sized + Pointee> Debug for PayloadPointer<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PayloadPointer").field("ptr", &self.ptr).field("meta", &self.meta).finish()
+    }
+}
+
+impl<T: ?Sized + Pointee> PartialEq for PayloadPointer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr == other.ptr && self.meta == other.meta
+    }
+}
+impl<T: ?Sized + Pointee> Eq for PayloadPointer<T> {}
+
+impl<T: ?Sized + Pointee> PartialOrd for PayloadPointer<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.ptr.partial_cmp(&other.ptr) {
+            Some(core::cmp::Ordering::Equal) => self.meta.partial_cmp(&other.meta),
+            ord => ord,
+        }
+    }
+}
+
 pub trait Pointee {
     type Metadata: Debug + Copy + Send + Sync + Ord + Hash + Unpin;
+
+    /// Rejoins a data address and metadata into a native pointer to `Self`, mirroring
+    /// std's (still unstable) `ptr::from_raw_parts`.
+    fn reconstruct(ptr: NonNull<()>, meta: Self::Metadata) -> *const Self;
 }
 
 impl<T> Pointee for [T] {
     type Metadata = usize;
+
+    fn reconstruct(ptr: NonNull<()>, meta: usize) -> *const Self {
+        ptr::slice_from_raw_parts(ptr.as_ptr().cast(), meta)
+    }
 }
 
 impl Pointee for str {
     type Metadata = usize;
+
+    fn reconstruct(ptr: NonNull<()>, meta: usize) -> *const Self {
+        ptr::slice_from_raw_parts(ptr.as_ptr().cast::<u8>(), meta) as *const str
+    }
+}
+
+// Blanket impl for every `Sized` type (the unbound `T` here defaults to `T: Sized`,
+// so this does not overlap the `?Sized` impls above). Lets `PayloadPointer<T>` work
+// uniformly for ordinary sized payloads, with `()` metadata.
+impl<T> Pointee for T {
+    type Metadata = ();
+
+    fn reconstruct(ptr: NonNull<()>, _meta: ()) -> *const Self {
+        ptr.as_ptr().cast()
+    }
+}
+
+#[repr(C)]
+struct FatPtrParts<M> {
+    data: *const (),
+    meta: M,
+}
+
+/// Reassembles a raw pointer from a thin data address and metadata, for the `?Sized`
+/// pointees that have no stable std constructor to do it for us (`dyn Trait`,
+/// `RawSlice2D`). Relies on every Rust pointer being laid out as `{ data, metadata }`,
+/// with the metadata erased entirely for thin pointers - the same assumption the
+/// `ptr_meta` family of crates leans on in place of the unstable `ptr::from_raw_parts`.
+fn reconstruct_ptr<T: ?Sized, M>(data: *const (), meta: M) -> *const T {
+    debug_assert_eq!(size_of::<FatPtrParts<M>>(), size_of::<*const T>());
+    let parts = FatPtrParts { data, meta };
+    unsafe { core::mem::transmute_copy(&parts) }
 }
 
+/// A `Pointee` whose metadata carries no information, i.e. an ordinary thin pointer.
+///
+/// Blanket-implemented for every type with `Metadata = ()`, which in practice means
+/// every `Sized` type (see the blanket [`Pointee`] impl above).
+pub trait Thin: Pointee<Metadata = ()> {}
+impl<T: Pointee<Metadata = ()>> Thin for T {}
+
 impl<T: ?Sized + Pointee> PayloadPointer<T> {
     /// Returns the metadata of the pointee.
     pub const fn metadata_of(self) -> T::Metadata {
@@ -42,10 +122,23 @@ impl<T: ?Sized + Pointee> PayloadPointer<T> {
         self.ptr.cast()
     }
 
+    /// Borrows the pointee for lifetime `'a`.
+    ///
+    /// # Safety
+    /// Equivalent to [`NonNull::as_ref`]: the pointee must be live, properly aligned,
+    /// and valid for reads for `'a`, and must not be mutated through another pointer
+    /// while the returned reference exists.
+    pub unsafe fn as_ref<'a>(self) -> &'a T {
+        unsafe { &*self.to_ptr() }
+    }
+
+    /// Mutably borrows the pointee for lifetime `'a`.
+    ///
     /// # Safety
-    /// equivalent to core::ptr::read on the pointee.
-    pub unsafe fn deref<P: Pointee + Sized>(pp: PayloadPointer<P>) -> P {
-        unsafe { core::ptr::read(pp.ptr.as_ptr().cast()) }
+    /// Equivalent to [`NonNull::as_mut`]: as [`Self::as_ref`], plus no other reference
+    /// (shared or exclusive) to the pointee may exist for `'a`.
+    pub unsafe fn as_mut<'a>(self) -> &'a mut T {
+        unsafe { &mut *(self.to_ptr() as *mut T) }
     }
 
     pub const fn from_raw_parts(ptr: NonNull<()>, meta: <T as Pointee>::Metadata) -> PayloadPointer<T> {
@@ -62,6 +155,38 @@ impl<T: ?Sized + Pointee> PayloadPointer<T> {
     {
         (self.ptr.cast(), self.meta)
     }
+
+    /// Rejoins this pointer's address and metadata into a native `*const T`.
+    pub fn to_ptr(self) -> *const T {
+        T::reconstruct(self.ptr, self.meta)
+    }
+
+    /// Rejoins this pointer's address and metadata into a native `NonNull<T>`.
+    pub fn to_non_null(self) -> NonNull<T> {
+        unsafe { NonNull::new_unchecked(self.to_ptr() as *mut T) }
+    }
+
+    /// # Safety
+    /// Equivalent to `core::ptr::read` on the pointee: the pointee must be live and
+    /// properly aligned, and reading it must not leave a stale copy behind that is
+    /// later dropped or aliased.
+    pub unsafe fn read(self) -> T
+    where
+        T: Sized,
+    {
+        unsafe { self.ptr.cast::<T>().as_ptr().read() }
+    }
+
+    /// # Safety
+    /// Equivalent to `core::ptr::write` on the pointee: the destination must be valid
+    /// for writes and properly aligned; any value previously stored there is
+    /// overwritten without being dropped.
+    pub unsafe fn write(self, val: T)
+    where
+        T: Sized,
+    {
+        unsafe { self.ptr.cast::<T>().as_ptr().write(val) };
+    }
 }
 
 impl<T> PayloadPointer<[T]> {
@@ -72,12 +197,37 @@ impl<T> PayloadPointer<[T]> {
         let sc = ptr::slice_from_raw_parts_mut(self.ptr.as_ptr().cast(), self.meta);
         unsafe { NonNull::new_unchecked(sc) }
     }
+
+    /// # Safety
+    /// Same as [`PayloadPointer::as_ref`].
+    pub unsafe fn as_slice<'a>(self) -> &'a [T] {
+        unsafe { self.to_raw_slice().as_ref() }
+    }
+
+    /// # Safety
+    /// Same as [`PayloadPointer::as_mut`].
+    pub unsafe fn as_mut_slice<'a>(self) -> &'a mut [T] {
+        unsafe { self.to_raw_slice().as_mut() }
+    }
 }
 
 impl PayloadPointer<str> {
     pub const fn to_raw_str(self) -> *const str {
         ptr::slice_from_raw_parts(self.ptr.as_ptr().cast::<u8>(), self.meta) as *const str
     }
+
+    /// # Safety
+    /// Same as [`PayloadPointer::as_ref`], plus the bytes must be valid UTF-8.
+    pub unsafe fn as_str<'a>(self) -> &'a str {
+        unsafe { &*self.to_raw_str() }
+    }
+
+    /// # Safety
+    /// Same as [`PayloadPointer::as_mut`], plus the bytes must be valid UTF-8 both
+    /// before the call and whenever they are next read through any other pointer.
+    pub unsafe fn as_mut_str<'a>(self) -> &'a mut str {
+        unsafe { &mut *(self.to_raw_str() as *mut str) }
+    }
 }
 
 pub unsafe trait GetRawPtr<AddrSource: ?Sized>
@@ -100,17 +250,361 @@ where
 
 unsafe impl<T> GetRawPtr<[T]> for [T] {}
 
+#[test]
+fn test_to_ptr_round_trip() {
+    let data = [1, 2, 3, 4];
+    let slice_pp = <[i32]>::get_raw_const_ptr_from_ref(&data, data.len());
+    assert_eq!(unsafe { &*slice_pp.to_ptr() }, &data);
+    assert_eq!(unsafe { slice_pp.to_non_null().as_ref() }, &data);
+
+    let mut value = 42i32;
+    let nn = unsafe { NonNull::new_unchecked(&mut value as *mut i32 as *mut ()) };
+    let sized_pp = PayloadPointer::<i32>::from_raw_parts(nn, ());
+    assert_eq!(unsafe { *sized_pp.to_ptr() }, 42);
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// Opt-in bridge from a live Rust reference/pointer to a `PayloadPointer` carrying the
+/// payload's *real* metadata (e.g. the true `len()` of a slice or `str`), instead of
+/// metadata supplied by hand through [`GetRawPtr`].
+///
+/// Not every `Pointee` can implement this: `RawSlice2D` and other metadata-only marker
+/// types have no native Rust reference to recover metadata from, so they stick to the
+/// raw, hand-supplied-metadata `GetRawPtr` API.
+pub trait FromNative: Pointee {
+    fn from_ref(r: &Self) -> PayloadPointer<Self>;
+    fn from_mut(r: &mut Self) -> PayloadPointer<Self>;
+
+    /// # Safety
+    /// `ptr` must be valid for reads and must point to a live `Self` whose real size
+    /// matches what this impl recovers from it (e.g. a slice allocation of the length
+    /// `ptr` itself reports).
+    unsafe fn from_native_ptr(ptr: *const Self) -> PayloadPointer<Self>;
+}
+
+impl<T> FromNative for [T] {
+    fn from_ref(r: &Self) -> PayloadPointer<Self> {
+        let nn = unsafe { NonNull::new_unchecked(r.as_ptr() as *mut ()) };
+        PayloadPointer::from_raw_parts(nn, r.len())
+    }
+
+    fn from_mut(r: &mut Self) -> PayloadPointer<Self> {
+        let len = r.len();
+        let nn = unsafe { NonNull::new_unchecked(r.as_mut_ptr() as *mut ()) };
+        PayloadPointer::from_raw_parts(nn, len)
+    }
+
+    unsafe fn from_native_ptr(ptr: *const Self) -> PayloadPointer<Self> {
+        let nn = unsafe { NonNull::new_unchecked(ptr as *mut ()) };
+        PayloadPointer::from_raw_parts(nn, ptr.len())
+    }
+}
+
+impl FromNative for str {
+    fn from_ref(r: &Self) -> PayloadPointer<Self> {
+        let nn = unsafe { NonNull::new_unchecked(r.as_ptr() as *mut ()) };
+        PayloadPointer::from_raw_parts(nn, r.len())
+    }
+
+    fn from_mut(r: &mut Self) -> PayloadPointer<Self> {
+        let len = r.len();
+        let nn = unsafe { NonNull::new_unchecked(r.as_mut_ptr() as *mut ()) };
+        PayloadPointer::from_raw_parts(nn, len)
+    }
+
+    unsafe fn from_native_ptr(ptr: *const Self) -> PayloadPointer<Self> {
+        let len = unsafe { (&*ptr).len() };
+        let nn = unsafe { NonNull::new_unchecked(ptr as *mut ()) };
+        PayloadPointer::from_raw_parts(nn, len)
+    }
+}
+
+impl<T: Thin> FromNative for T {
+    fn from_ref(r: &Self) -> PayloadPointer<Self> {
+        let nn = unsafe { NonNull::new_unchecked(r as *const T as *mut ()) };
+        PayloadPointer::from_raw_parts(nn, ())
+    }
+
+    fn from_mut(r: &mut Self) -> PayloadPointer<Self> {
+        let nn = unsafe { NonNull::new_unchecked(r as *mut T as *mut ()) };
+        PayloadPointer::from_raw_parts(nn, ())
+    }
+
+    unsafe fn from_native_ptr(ptr: *const Self) -> PayloadPointer<Self> {
+        let nn = unsafe { NonNull::new_unchecked(ptr as *mut ()) };
+        PayloadPointer::from_raw_parts(nn, ())
+    }
+}
+
+impl<T: ?Sized + FromNative> PayloadPointer<T> {
+    pub fn from_ref(r: &T) -> Self {
+        T::from_ref(r)
+    }
+
+    pub fn from_mut(r: &mut T) -> Self {
+        T::from_mut(r)
+    }
+
+    /// # Safety
+    /// See [`FromNative::from_native_ptr`].
+    pub unsafe fn from_native_ptr(ptr: *const T) -> Self {
+        unsafe { T::from_native_ptr(ptr) }
+    }
+}
+
+#[test]
+fn test_from_native() {
+    let data = [1, 2, 3, 4, 5];
+    let slice_pp = PayloadPointer::<[i32]>::from_ref(&data);
+    assert_eq!(slice_pp.metadata_of(), data.len());
+    assert_eq!(unsafe { &*slice_pp.to_ptr() }, &data);
+
+    let text = "hello";
+    let str_pp = PayloadPointer::<str>::from_ref(text);
+    assert_eq!(str_pp.metadata_of(), text.len());
+
+    let mut value = 7u32;
+    let sized_pp = PayloadPointer::<u32>::from_mut(&mut value);
+    assert_eq!(unsafe { *sized_pp.to_ptr() }, 7);
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// The leading layout rustc emits for every vtable: drop glue, then size, then align,
+/// followed by the trait's method slots (which we never need to name).
+#[repr(C)]
+pub struct VTableHeader {
+    pub drop_in_place: Option<unsafe fn(*mut ())>,
+    pub size: usize,
+    pub align: usize,
+}
+
+/// Metadata for a `dyn Trait` pointee: a vtable address, the way RFC 2580 intends.
+///
+/// Two `DynMetadata` values compare equal (and hash equal) iff they point at the
+/// same vtable address. The concrete type is not compared: two distinct vtables for
+/// the same type (e.g. a hand-built `VTableHeader` and rustc's own) compare unequal.
+pub struct DynMetadata<Dyn: ?Sized> {
+    vtable: NonNull<VTableHeader>,
+    // `fn() -> Dyn` rather than `Dyn` directly: trait objects aren't `Unpin` by
+    // default, and `Metadata` must be. The vtable address carries no real borrow of
+    // `Dyn`, so this variance-only phantom is the right shape either way.
+    _marker: PhantomData<fn() -> Dyn>,
+}
+
+impl<Dyn: ?Sized> DynMetadata<Dyn> {
+    /// # Safety
+    /// `vtable` must point to a valid vtable for `Dyn`, laid out with a `VTableHeader`
+    /// as its leading fields (this matches rustc's current `dyn Trait` vtable layout).
+    pub const unsafe fn new(vtable: NonNull<VTableHeader>) -> Self {
+        DynMetadata {
+            vtable,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn size_of(self) -> usize {
+        unsafe { self.vtable.as_ref().size }
+    }
+
+    pub fn align_of(self) -> usize {
+        unsafe { self.vtable.as_ref().align }
+    }
+
+    /// # Safety
+    /// `data` must point to a live, properly aligned value of the concrete type
+    /// this vtable was built for, and must not be used again afterwards.
+    pub unsafe fn drop_in_place(self, data: *mut ()) {
+        if let Some(drop_fn) = unsafe { self.vtable.as_ref().drop_in_place } {
+            unsafe { drop_fn(data) };
+        }
+    }
+}
+
+impl<Dyn: ?Sized> Clone for DynMetadata<Dyn> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<Dyn: ?Sized> Copy for DynMetadata<Dyn> {}
+
+impl<Dyn: ?Sized> Debug for DynMetadata<Dyn> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DynMetadata").field("vtable", &self.vtable).finish()
+    }
+}
+
+impl<Dyn: ?Sized> PartialEq for DynMetadata<Dyn> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vtable == other.vtable
+    }
+}
+impl<Dyn: ?Sized> Eq for DynMetadata<Dyn> {}
+
+impl<Dyn: ?Sized> PartialOrd for DynMetadata<Dyn> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<Dyn: ?Sized> Ord for DynMetadata<Dyn> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.vtable.cmp(&other.vtable)
+    }
+}
+
+impl<Dyn: ?Sized> Hash for DynMetadata<Dyn> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.vtable.hash(state);
+    }
+}
+
+// SAFETY: a `DynMetadata` is just a vtable address; vtables are `'static` and shared.
+unsafe impl<Dyn: ?Sized> Send for DynMetadata<Dyn> {}
+unsafe impl<Dyn: ?Sized> Sync for DynMetadata<Dyn> {}
+
+/// Implements `Pointee` for `dyn $trait` with `Metadata = DynMetadata<dyn $trait>`.
+///
+/// Invoke once per trait you want to store behind a `PayloadPointer`:
+/// `metadata_for_dyn!(MyTrait);`
+#[macro_export]
+macro_rules! metadata_for_dyn {
+    ($t:path) => {
+        impl $crate::Pointee for dyn $t {
+            type Metadata = $crate::DynMetadata<dyn $t>;
+
+            fn reconstruct(ptr: ::std::ptr::NonNull<()>, meta: Self::Metadata) -> *const Self {
+                $crate::__reconstruct_dyn(ptr, meta)
+            }
+        }
+    };
+}
+
+/// Not part of the public API; used by [`metadata_for_dyn!`] to assemble a
+/// `*const dyn Trait` from a data address and vtable, since there's no stable way
+/// for a macro-generated impl to do it inline.
+#[doc(hidden)]
+pub fn __reconstruct_dyn<Dyn: ?Sized>(ptr: NonNull<()>, meta: DynMetadata<Dyn>) -> *const Dyn {
+    reconstruct_ptr(ptr.as_ptr(), meta)
+}
+
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> PayloadPointer<Dyn> {
+    pub fn size_of_val(self) -> usize {
+        self.meta.size_of()
+    }
+
+    pub fn align_of_val(self) -> usize {
+        self.meta.align_of()
+    }
+
+    /// # Safety
+    /// Equivalent to `core::ptr::drop_in_place`: the pointee must be live, properly
+    /// aligned, and must not be accessed (or dropped again) afterwards.
+    pub unsafe fn drop_in_place(self) {
+        unsafe { self.meta.drop_in_place(self.ptr.as_ptr()) };
+    }
+}
+
+#[test]
+fn test_dyn_metadata() {
+    trait Greet {
+        fn greet(&self) -> &str;
+    }
+    metadata_for_dyn!(Greet);
+
+    struct Loud(String);
+    impl Greet for Loud {
+        fn greet(&self) -> &str {
+            &self.0
+        }
+    }
+
+    unsafe fn drop_loud(ptr: *mut ()) {
+        unsafe { ptr::drop_in_place(ptr as *mut Loud) };
+    }
+
+    let mut header = VTableHeader {
+        drop_in_place: Some(drop_loud),
+        size: size_of::<Loud>(),
+        align: align_of::<Loud>(),
+    };
+    let meta = unsafe { DynMetadata::<dyn Greet>::new(NonNull::from(&mut header)) };
+
+    let mut value = Loud("hello".to_string());
+    assert_eq!(value.greet(), "hello");
+    let nn = unsafe { NonNull::new_unchecked(&mut value as *mut Loud as *mut ()) };
+    let pp = PayloadPointer::<dyn Greet>::from_raw_parts(nn, meta);
+
+    assert_eq!(pp.size_of_val(), size_of::<Loud>());
+    assert_eq!(pp.align_of_val(), align_of::<Loud>());
+
+    core::mem::forget(value);
+    unsafe { pp.drop_in_place() };
+}
+
+#[test]
+fn test_dyn_metadata_real_vtable() {
+    trait Greet {
+        fn greet(&self) -> &str;
+    }
+    metadata_for_dyn!(Greet);
+
+    struct Loud(String);
+    impl Greet for Loud {
+        fn greet(&self) -> &str {
+            &self.0
+        }
+    }
+
+    // Every fat pointer, including `&dyn Trait`, is laid out as `{ data, vtable }` -
+    // the same assumption `reconstruct_ptr` documents. Transmuting the real
+    // `&dyn Greet` fat pointer into this shape recovers rustc's actual vtable address,
+    // whose leading fields `VTableHeader` describes, instead of fabricating one by hand.
+    #[repr(C)]
+    struct FatPtrParts {
+        data: *const (),
+        vtable: *const VTableHeader,
+    }
+
+    let value = Loud("hello".to_string());
+    assert_eq!(value.greet(), "hello");
+    let wide: &dyn Greet = &value;
+    let parts: FatPtrParts = unsafe { core::mem::transmute(wide) };
+
+    let meta = unsafe { DynMetadata::<dyn Greet>::new(NonNull::new_unchecked(parts.vtable as *mut VTableHeader)) };
+    assert_eq!(meta.size_of(), size_of::<Loud>());
+    assert_eq!(meta.align_of(), align_of::<Loud>());
+
+    let nn = unsafe { NonNull::new_unchecked(parts.data as *mut ()) };
+    let pp = PayloadPointer::<dyn Greet>::from_raw_parts(nn, meta);
+    assert_eq!(pp.size_of_val(), size_of::<Loud>());
+    assert_eq!(pp.align_of_val(), align_of::<Loud>());
+
+    core::mem::forget(value);
+    unsafe { pp.drop_in_place() };
+}
+
 // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
 
-#[derive(Clone, Copy)]
+// `RawSlice2D<T>` is a marker type standing in for "a 2-D run of `T`s": it is never
+// constructed as a value, only ever used as a `PayloadPointer` type parameter. The
+// trailing `[()]` field makes it genuinely `?Sized`, so it does not collide with the
+// blanket `impl<T> Pointee for T` above, which only covers `Sized` types.
 pub struct RawSlice2D<T> {
     _marker: PhantomData<T>,
+    _unsized: [()],
 }
 
 unsafe impl<T> GetRawPtr<[T]> for RawSlice2D<T> {}
 impl<T> Pointee for RawSlice2D<T> {
     // lenx, leny. slice2d[meta.0 - 1][meta.1 - 1] always succeeds.
     type Metadata = (usize, usize);
+
+    fn reconstruct(ptr: NonNull<()>, meta: (usize, usize)) -> *const Self {
+        // The trailing `[()]` field makes rustc's own metadata for this pointer a
+        // single `usize`; the cell count is as good a convention as any for it.
+        reconstruct_ptr(ptr.as_ptr(), meta.0 * meta.1)
+    }
 }
 #[test]
 fn test_2d_slice() {
@@ -122,10 +616,261 @@ fn test_2d_slice() {
 
     let slice2d = RawSlice2D::get_raw_const_ptr_from_ref(&data, (3, 3));
     println!(
-        "Addr: {:p}\nHorizontal Len: {}, Vertical Len: {}\nMOST IMPORTANTLY, size_of::<PayloadPointer<RawSlice2D<i32>>>(): {}",
-        slice2d.as_ptr().as_ptr(),
+        "Addr: {:#x}\nHorizontal Len: {}, Vertical Len: {}\nMOST IMPORTANTLY, size_of::<PayloadPointer<RawSlice2D<i32>>>(): {}",
+        slice2d.addr(),
         slice2d.metadata_of().0,
         slice2d.metadata_of().1,
         size_of::<PayloadPointer<RawSlice2D<i32>>>()
     );
 }
+
+impl<T> PayloadPointer<RawSlice2D<T>> {
+    fn base(self) -> *const T {
+        self.ptr.as_ptr().cast()
+    }
+
+    /// # Safety
+    /// The pointee must be a live, row-major `lenx * leny` array of `T` whose true
+    /// extents match this pointer's `(lenx, leny)` metadata.
+    pub unsafe fn get(self, x: usize, y: usize) -> Option<*const T> {
+        let (lenx, leny) = self.meta;
+        if x >= lenx || y >= leny {
+            return None;
+        }
+        Some(unsafe { self.get_unchecked(x, y) })
+    }
+
+    /// # Safety
+    /// Same as [`Self::get`], plus the caller must ensure `x < lenx` and `y < leny`.
+    pub unsafe fn get_unchecked(self, x: usize, y: usize) -> *const T {
+        let (lenx, _) = self.meta;
+        unsafe { self.base().add(y * lenx + x) }
+    }
+
+    /// Row `y` as a contiguous `PayloadPointer<[T]>`, or `None` if `y` is out of range.
+    ///
+    /// # Safety
+    /// Same preconditions as [`Self::get`].
+    pub unsafe fn row(self, y: usize) -> Option<PayloadPointer<[T]>> {
+        let (lenx, leny) = self.meta;
+        if y >= leny {
+            return None;
+        }
+        let row_ptr = unsafe { self.base().add(y * lenx) };
+        let nn = unsafe { NonNull::new_unchecked(row_ptr as *mut ()) };
+        Some(PayloadPointer::from_raw_parts(nn, lenx))
+    }
+
+    /// Column `x`, as an iterator over `leny` strided elements, or `None` if `x` is
+    /// out of range.
+    ///
+    /// Unlike [`Self::row`] this can't be a `PayloadPointer<[T]>`: a column isn't
+    /// contiguous, and `[T]`'s metadata has no room for a stride.
+    ///
+    /// # Safety
+    /// Same preconditions as [`Self::get`].
+    pub unsafe fn col(self, x: usize) -> Option<ColIter<T>> {
+        let (lenx, leny) = self.meta;
+        if x >= lenx {
+            return None;
+        }
+        Some(ColIter {
+            ptr: unsafe { self.base().add(x) },
+            stride: lenx,
+            remaining: leny,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Iterates over all `leny` rows, in order.
+    ///
+    /// # Safety
+    /// Same preconditions as [`Self::get`].
+    pub unsafe fn rows(self) -> RowsIter<T> {
+        RowsIter { slice2d: self, next_row: 0 }
+    }
+
+    /// Iterates over all `lenx * leny` elements in row-major order.
+    ///
+    /// # Safety
+    /// Same preconditions as [`Self::get`].
+    pub unsafe fn iter(self) -> Iter2D<T> {
+        let (lenx, leny) = self.meta;
+        Iter2D {
+            ptr: self.base(),
+            remaining: lenx * leny,
+            _marker: PhantomData,
+        }
+    }
+}
+
+pub struct ColIter<T> {
+    ptr: *const T,
+    stride: usize,
+    remaining: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Iterator for ColIter<T> {
+    type Item = *const T;
+
+    fn next(&mut self) -> Option<*const T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let cur = self.ptr;
+        self.remaining -= 1;
+        // Only advance when another element remains: `ptr::add`'s contract requires
+        // the whole stepped range to stay in bounds of the allocation, and stepping
+        // past the last element by a full `stride` can walk off the end of it.
+        if self.remaining > 0 {
+            self.ptr = unsafe { self.ptr.add(self.stride) };
+        }
+        Some(cur)
+    }
+}
+
+pub struct RowsIter<T> {
+    slice2d: PayloadPointer<RawSlice2D<T>>,
+    next_row: usize,
+}
+
+impl<T> Iterator for RowsIter<T> {
+    type Item = PayloadPointer<[T]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row = unsafe { self.slice2d.row(self.next_row) }?;
+        self.next_row += 1;
+        Some(row)
+    }
+}
+
+pub struct Iter2D<T> {
+    ptr: *const T,
+    remaining: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Iterator for Iter2D<T> {
+    type Item = *const T;
+
+    fn next(&mut self) -> Option<*const T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let cur = self.ptr;
+        self.ptr = unsafe { self.ptr.add(1) };
+        self.remaining -= 1;
+        Some(cur)
+    }
+}
+
+#[test]
+fn test_2d_access() {
+    // Row-major 3x3 grid: row y occupies data[y * 3..][..3].
+    let data = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+
+    let slice2d = RawSlice2D::<i32>::get_raw_const_ptr_from_ref(&data, (3, 3));
+
+    unsafe {
+        assert_eq!(*slice2d.get(1, 2).unwrap(), 7);
+        assert_eq!(slice2d.get(3, 0), None);
+        assert_eq!(*slice2d.get_unchecked(2, 1), 5);
+
+        let row1 = slice2d.row(1).unwrap();
+        assert_eq!(row1.to_non_null().as_ref(), &[3, 4, 5]);
+
+        let col1: Vec<i32> = slice2d.col(1).unwrap().map(|p| *p).collect();
+        assert_eq!(col1, [1, 4, 7]);
+
+        let flattened: Vec<i32> = slice2d.iter().map(|p| *p).collect();
+        assert_eq!(flattened, data);
+
+        let row_count = slice2d.rows().count();
+        assert_eq!(row_count, 3);
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+// `RawSliceND<T, N>` generalizes `RawSlice2D` to an arbitrary number of dimensions;
+// same marker-type trick (trailing `[()]`), `Metadata` is now a full extent array.
+pub struct RawSliceND<T, const N: usize> {
+    _marker: PhantomData<T>,
+    _unsized: [()],
+}
+
+unsafe impl<T, const N: usize> GetRawPtr<[T]> for RawSliceND<T, N> {}
+impl<T, const N: usize> Pointee for RawSliceND<T, N> {
+    // extents[i] is the length along axis i; axis 0 is the slowest-varying (outermost)
+    // and axis N-1 is contiguous, i.e. standard row-major order.
+    type Metadata = [usize; N];
+
+    fn reconstruct(ptr: NonNull<()>, meta: [usize; N]) -> *const Self {
+        reconstruct_ptr(ptr.as_ptr(), meta.iter().product::<usize>())
+    }
+}
+
+/// Folds a row-major N-dimensional coordinate against an extent array into a flat
+/// index, returning `None` if any axis is out of bounds.
+pub fn linear_index<const N: usize>(coords: [usize; N], extents: [usize; N]) -> Option<usize> {
+    let mut index = 0usize;
+    for axis in 0..N {
+        if coords[axis] >= extents[axis] {
+            return None;
+        }
+        index = index * extents[axis] + coords[axis];
+    }
+    Some(index)
+}
+
+#[test]
+fn test_nd_linear_index() {
+    let extents = [2, 3, 4];
+    assert_eq!(linear_index([0, 0, 0], extents), Some(0));
+    assert_eq!(linear_index([1, 2, 3], extents), Some(23));
+    assert_eq!(linear_index([0, 0, 4], extents), None);
+    assert_eq!(linear_index([2, 0, 0], extents), None);
+}
+
+#[test]
+fn test_nd_slice_round_trip() {
+    let extents = [2, 3, 4];
+    let data: Vec<i32> = (0..extents.iter().product::<usize>() as i32).collect();
+
+    let slice_nd = RawSliceND::<i32, 3>::get_raw_const_ptr_from_ref(data.as_slice(), extents);
+    assert_eq!(slice_nd.metadata_of(), extents);
+    assert_eq!(slice_nd.addr(), data.as_ptr() as usize);
+    // `to_ptr`'s fat pointer carries no type-safe view of `T` (its metadata is just the
+    // element count, reused from the `RawSlice2D` trick), but its data address must
+    // still round-trip back to the original backing storage.
+    assert_eq!(slice_nd.to_ptr() as *const (), data.as_ptr() as *const ());
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+#[test]
+fn test_safe_views() {
+    let mut value = 42i32;
+    let sized_pp = PayloadPointer::<i32>::from_mut(&mut value);
+    assert_eq!(unsafe { sized_pp.as_ref() }, &42);
+    unsafe { *sized_pp.as_mut() = 43 };
+    assert_eq!(value, 43);
+
+    let pp = PayloadPointer::<i32>::from_mut(&mut value);
+    assert_eq!(unsafe { pp.read() }, 43);
+    unsafe { pp.write(7) };
+    assert_eq!(value, 7);
+
+    let mut data = [1, 2, 3, 4];
+    let slice_pp = PayloadPointer::<[i32]>::from_mut(&mut data);
+    assert_eq!(unsafe { slice_pp.as_slice() }, &[1, 2, 3, 4]);
+    unsafe { slice_pp.as_mut_slice()[0] = 9 };
+    assert_eq!(data, [9, 2, 3, 4]);
+
+    let mut text = String::from("hello");
+    let str_pp = PayloadPointer::<str>::from_mut(&mut text);
+    assert_eq!(unsafe { str_pp.as_str() }, "hello");
+    unsafe { str_pp.as_mut_str().make_ascii_uppercase() };
+    assert_eq!(text, "HELLO");
+}